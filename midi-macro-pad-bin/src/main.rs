@@ -1,121 +1,112 @@
-use std::{env, fs};
-use std::vec::Vec;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::{Arc, RwLock};
+
+use clap::{Parser, Subcommand};
 
 use midi_macro_pad_lib::{focus, state};
-use midi_macro_pad_lib::config::Config;
-use midi_macro_pad_lib::config::input_formats::get_parser_for_extension;
-use midi_macro_pad_lib::macros::actions::ActionRunner;
+use midi_macro_pad_lib::config::loader::{load_config_file, resolve_config_path};
+use midi_macro_pad_lib::config::watcher::watch_config;
+use midi_macro_pad_lib::error::MmpdError;
+use midi_macro_pad_lib::macros::actions::{ActionRunner, Context};
 use midi_macro_pad_lib::macros::event_matching::Event;
 use midi_macro_pad_lib::macros::event_matching::midi::MidiEventMatcher;
 use midi_macro_pad_lib::match_checker::{MatchChecker, NumberMatcher};
 use midi_macro_pad_lib::midi;
+use midi_macro_pad_lib::midi::MidiMessage;
+
+/// Maps MIDI input to configurable macros.
+#[derive(Parser)]
+#[command(name = "mmpd", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints a list of the available MIDI input ports.
+    ListPorts,
+
+    /// Connects to a MIDI port and starts executing macros as events come in.
+    Listen(ListenArgs),
+}
 
-fn main() {
+/// Arguments for the `listen` subcommand, and for the default (no-subcommand) invocation.
+#[derive(clap::Args)]
+struct ListenArgs {
+    /// Only connect to a port whose name contains this pattern. Matches any port if omitted.
+    #[arg(short, long, default_value = "")]
+    port: String,
+
+    /// Path to a config file to use, instead of searching the default locations.
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
     println!("MIDI Macro Pad starting.");
-    let args: Vec<String> = env::args().collect();
 
-    println!("Running with args:\n{:?}", args);
+    let cli = Cli::parse();
 
-    if let Some(cmd) = args.get(1) {
-        match cmd.as_str() {
-            "list-ports" => task_list_ports(),
-            "listen" => task_listen(args.get(2)),
+    let result = match cli.command {
+        Some(Command::ListPorts) => task_list_ports(),
+        Some(Command::Listen(args)) => task_listen(args),
 
-            _ => {
-                eprintln!("Unrecognised argument '{}'", cmd);
-                return;
-            }
-        }
+        // No subcommand given: load the config from the default location and listen on any port.
+        None => task_listen(ListenArgs { port: String::new(), config: None }),
+    };
 
-        return;
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        return ExitCode::FAILURE;
     }
 
-    // TODO: if no command is specified, load config file from default location
-    // TODO: otherwise, allow specifying config file from args too and use that
-
-    println!("Config file loading not yet implemented, exiting.");
+    ExitCode::SUCCESS
 }
 
 /// Prints a list of all available MIDI input devices connected to this computer to STDOUT.
 ///
-/// If the MIDI adapter cannot be initialized, prints an error.
-///
 /// The output of this is useful for specifying a port to listen to, see `task_listen`.
-fn task_list_ports() {
-    let midi_adapter = midi::get_adapter();
+fn task_list_ports() -> Result<(), MmpdError> {
+    let midi_adapter = midi::get_adapter().ok_or(MmpdError::MidiAdapterUnavailable)?;
 
-    if let None = midi_adapter {
-        eprintln!("Unable to initialize MIDI adapter.");
-        return;
-    }
-
-    let port_names = midi_adapter.unwrap().list_ports();
+    let port_names = midi_adapter.list_ports();
 
     println!("Available midi ports:");
 
     for port_name in port_names.iter() {
         println!("{}", port_name);
     }
+
+    Ok(())
 }
 
-/// Opens a connection on a port which' name contains port_pattern and begins listening for
+/// Opens a connection on a port which' name contains `args.port` and begins listening for
 /// MIDI messages.
 ///
 /// Each message will be parsed and printed to STDOUT.
 ///
 /// Some filters are hardcoded at the moment and will execute a key sequence when it occurs.
-fn task_listen(port_pattern: Option<&String>) -> () {
-    if let None = port_pattern {
-        eprintln!("No port pattern specified");
-        return ();
-    }
-
-    let port_pattern = port_pattern.unwrap();
+fn task_listen(args: ListenArgs) -> Result<(), MmpdError> {
+    let port_pattern = args.port;
 
     let (tx, rx) = midi::get_midi_bus();
 
-    let midi_adapter = midi::get_adapter();
+    let mut midi_adapter = midi::get_adapter().ok_or(MmpdError::MidiAdapterUnavailable)?;
 
-    if let None = midi_adapter {
-        eprintln!("Unable to set up midi adapter");
-        return;
-    }
-
-    let mut midi_adapter = midi_adapter.unwrap();
-
-    let focus_adapter = focus::get_adapter();
-
-    if let None = focus_adapter {
-        eprintln!("Unable to set up focus adapter - can't detect focused window.");
-        return;
-    }
-
-    let focus_adapter = focus_adapter.unwrap();
-
-    let handle = midi_adapter.start_listening(String::from(port_pattern), tx);
-
-    if let None = handle {
-        eprintln!("Unable to start listening for MIDI events.");
-        return;
-    }
+    let focus_adapter = focus::get_adapter().ok_or(MmpdError::FocusAdapterUnavailable)?;
 
-    let action_runner = ActionRunner::new();
+    midi_adapter.start_listening(port_pattern, tx).ok_or(MmpdError::MidiListenFailed)?;
 
-    if let None = action_runner {
-        eprintln!("Unable to get an action runner.");
-        return;
-    }
-
-    let action_runner = action_runner.unwrap();
+    let action_runner = ActionRunner::new().ok_or(MmpdError::ActionRunnerUnavailable)?;
     let state = state::new(focus_adapter);
 
-    let config = get_config();
+    let config_path = resolve_config_path(args.config)?;
+    println!("Loading config from: {}", config_path.display());
 
-    if let None = config {
-        return;
-    }
-
-    let config = config.unwrap();
+    let config = Arc::new(RwLock::new(load_config_file(&config_path)?));
+    watch_config(config_path, config.clone());
 
     let stop_matcher = MidiEventMatcher::ControlChange {
         channel_match: None,
@@ -127,6 +118,8 @@ fn task_listen(port_pattern: Option<&String>) -> () {
         //println!("{:?}", msg);
 
         let event = Event::Midi(&msg);
+        let context = context_for_midi_message(&msg);
+        let config = config.read().unwrap_or_else(|p| p.into_inner());
 
         for macro_item in config.macros.iter() {
             if let Some(actions) = macro_item.evaluate(&event, &state) {
@@ -137,7 +130,7 @@ fn task_listen(port_pattern: Option<&String>) -> () {
                 }
 
                 for action in actions {
-                    action_runner.run(action);
+                    action_runner.run_with_context(action, &context);
                 }
 
                 break;
@@ -149,25 +142,38 @@ fn task_listen(port_pattern: Option<&String>) -> () {
         }
     }
 
+    action_runner.release_held_keys();
+
     println!("Exiting.");
+
+    Ok(())
 }
 
-fn get_config() -> Option<Config> {
-    let filename = "testcfg.yml";
-    let config_text = fs::read_to_string(filename).unwrap();
-    let parser = get_parser_for_extension("yml").unwrap();
-    let raw_config = parser.parse(&config_text);
-
-    if let Ok(rc) = raw_config {
-        match rc.process() {
-            Ok(config) => Some(config),
-            Err(e) => {
-                eprintln!("Error loading config: {}", e.description());
-                None
-            }
-        }
-    } else {
-        eprintln!("Error: No raw config loaded");
-        None
+/// Builds a `Context` of substitutable variables (e.g. `note`, `velocity`, `channel`, `control`,
+/// `value`) out of the fields of a single incoming `msg`, for use with
+/// `ActionRunner::run_with_context`.
+fn context_for_midi_message(msg: &MidiMessage) -> Context {
+    let mut context = Context::new();
+
+    match msg {
+        MidiMessage::NoteOn { channel, note, velocity } => {
+            context.insert("channel", channel.to_string());
+            context.insert("note", note.to_string());
+            context.insert("velocity", velocity.to_string());
+        },
+
+        MidiMessage::NoteOff { channel, note, velocity } => {
+            context.insert("channel", channel.to_string());
+            context.insert("note", note.to_string());
+            context.insert("velocity", velocity.to_string());
+        },
+
+        MidiMessage::ControlChange { channel, control, value } => {
+            context.insert("channel", channel.to_string());
+            context.insert("control", control.to_string());
+            context.insert("value", value.to_string());
+        },
     }
+
+    context
 }
@@ -0,0 +1,27 @@
+pub mod discovery;
+pub mod input_formats;
+pub mod loader;
+pub mod raw_config;
+pub mod versions;
+pub mod watcher;
+
+use crate::macros::Macro;
+
+/// A fully parsed and processed config, ready to be matched against incoming events.
+pub struct Config {
+    pub macros: Vec<Macro>,
+}
+
+/// Error returned while processing a parsed, version-tagged raw config into a `Config`.
+#[derive(Debug)]
+pub enum ConfigError {
+    InvalidConfig(String),
+}
+
+impl ConfigError {
+    pub fn description(&self) -> String {
+        match self {
+            ConfigError::InvalidConfig(message) => message.clone(),
+        }
+    }
+}
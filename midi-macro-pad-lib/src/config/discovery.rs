@@ -0,0 +1,182 @@
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::input_formats::get_parser_for_extension;
+
+/// Returned by `discover_config_path` when no config file could be found: either none of the
+/// searched locations contain one with a recognised extension, or `$MMPD_CONFIG` was set but
+/// doesn't point at an existing file.
+#[derive(Debug)]
+pub struct ConfigNotFoundError {
+    searched: Vec<PathBuf>,
+    env_override: Option<PathBuf>,
+}
+
+impl ConfigNotFoundError {
+    pub fn description(&self) -> String {
+        if let Some(env_override) = &self.env_override {
+            return format!(
+                "MMPD_CONFIG is set to '{}', but no file exists there.",
+                env_override.display()
+            );
+        }
+
+        format!(
+            "Could not find a config file. Searched: {}",
+            self.searched.iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Display for ConfigNotFoundError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+/// Searches the standard locations, in order, for a config file named `config.<ext>` where `ext`
+/// is any extension recognised by `get_parser_for_extension`:
+///
+/// 1. The path given by the `MMPD_CONFIG` environment variable, if set.
+/// 2. `$XDG_CONFIG_HOME/mmpd/`, falling back to `~/.config/mmpd/` if `XDG_CONFIG_HOME` isn't set.
+/// 3. `/etc/mmpd/`
+///
+/// Returns the first matching path found, or a `ConfigNotFoundError` describing the problem, so
+/// callers can report a clear message back to the user.
+///
+/// If `MMPD_CONFIG` is set but doesn't point at an existing file, this returns immediately with
+/// an error naming that override rather than silently falling back to the other locations, since
+/// a typo'd `MMPD_CONFIG` is more likely a mistake than an invitation to search elsewhere.
+pub fn discover_config_path() -> Result<PathBuf, ConfigNotFoundError> {
+    if let Ok(env_path) = env::var("MMPD_CONFIG") {
+        let env_path = PathBuf::from(env_path);
+
+        if env_path.is_file() {
+            return Ok(env_path);
+        }
+
+        return Err(ConfigNotFoundError { searched: vec![], env_override: Some(env_path) });
+    }
+
+    let mut searched = vec![];
+
+    for dir in config_dirs() {
+        if let Some(path) = find_config_in_dir(&dir) {
+            return Ok(path);
+        }
+
+        searched.push(dir.join("config.*"));
+    }
+
+    Err(ConfigNotFoundError { searched, env_override: None })
+}
+
+/// Returns the directories to search for a config file, in search order.
+fn config_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+
+    let xdg_config_home = env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")));
+
+    if let Ok(xdg_config_home) = xdg_config_home {
+        dirs.push(xdg_config_home.join("mmpd"));
+    }
+
+    dirs.push(PathBuf::from("/etc/mmpd"));
+
+    dirs
+}
+
+/// Looks in `dir` for a file named `config.<ext>` where `ext` is recognised by
+/// `get_parser_for_extension`.
+fn find_config_in_dir(dir: &Path) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(dir).ok()?;
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.file_stem().and_then(|stem| stem.to_str()) == Some("config"))
+        .find(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| get_parser_for_extension(ext).is_some())
+                .unwrap_or(false)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// `discover_config_path` reads process-wide environment variables, so tests that touch
+    /// `MMPD_CONFIG`/`XDG_CONFIG_HOME`/`HOME` serialize on this to avoid racing each other.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+    static TEST_DIR_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn unique_test_dir() -> PathBuf {
+        let n = TEST_DIR_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = env::temp_dir().join(format!("mmpd-discovery-test-{}-{}", std::process::id(), n));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn find_config_in_dir_finds_a_recognised_extension() {
+        let dir = unique_test_dir();
+        std::fs::write(dir.join("config.yaml"), "").unwrap();
+
+        assert_eq!(find_config_in_dir(&dir), Some(dir.join("config.yaml")));
+    }
+
+    #[test]
+    fn find_config_in_dir_ignores_unrecognised_extensions_and_other_files() {
+        let dir = unique_test_dir();
+        std::fs::write(dir.join("config.unknownext"), "").unwrap();
+        std::fs::write(dir.join("notconfig.yaml"), "").unwrap();
+
+        assert_eq!(find_config_in_dir(&dir), None);
+    }
+
+    #[test]
+    fn find_config_in_dir_returns_none_for_a_missing_dir() {
+        let dir = env::temp_dir().join("mmpd-discovery-test-does-not-exist");
+
+        assert_eq!(find_config_in_dir(&dir), None);
+    }
+
+    #[test]
+    fn discover_config_path_uses_mmpd_config_when_it_points_at_a_file() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = unique_test_dir();
+        let config_path = dir.join("config.yaml");
+        std::fs::write(&config_path, "").unwrap();
+
+        env::set_var("MMPD_CONFIG", &config_path);
+        let result = discover_config_path();
+        env::remove_var("MMPD_CONFIG");
+
+        assert_eq!(result.unwrap(), config_path);
+    }
+
+    #[test]
+    fn discover_config_path_errors_distinctly_when_mmpd_config_is_invalid() {
+        let _guard = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let bad_path = env::temp_dir().join("mmpd-discovery-test-nonexistent-config.yaml");
+
+        env::set_var("MMPD_CONFIG", &bad_path);
+        let error = discover_config_path().unwrap_err();
+        env::remove_var("MMPD_CONFIG");
+
+        let message = error.description();
+        assert!(message.contains("MMPD_CONFIG"), "message was: {}", message);
+        assert!(message.contains(&bad_path.display().to_string()), "message was: {}", message);
+    }
+}
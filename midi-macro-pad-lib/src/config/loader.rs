@@ -0,0 +1,44 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+use crate::config::discovery::discover_config_path;
+use crate::config::input_formats::get_parser_for_extension;
+use crate::error::MmpdError;
+
+/// Resolves the config path to use: `config_path` itself if given, otherwise the first path found
+/// by `discover_config_path`.
+pub fn resolve_config_path(config_path: Option<PathBuf>) -> Result<PathBuf, MmpdError> {
+    match config_path {
+        Some(path) => Ok(path),
+        None => discover_config_path().map_err(|e| MmpdError::ConfigNotFound(e.description())),
+    }
+}
+
+/// Reads the config file at `path` and parses/processes it through the parser registered for its
+/// extension (see `get_parser_for_extension`).
+pub fn load_config_file(path: &Path) -> Result<Config, MmpdError> {
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+
+    let parser = get_parser_for_extension(extension)
+        .ok_or_else(|| MmpdError::UnrecognisedConfigExtension(extension.to_string()))?;
+
+    let config_text = fs::read_to_string(path)?;
+
+    let raw_config = parser.parse(&config_text)
+        .map_err(|_| MmpdError::InvalidConfig(format!("Could not parse config file")))?;
+
+    raw_config.process().map_err(|e| MmpdError::InvalidConfig(e.description()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_config_path_passes_through_an_explicit_path_without_discovery() {
+        let path = PathBuf::from("/some/explicit/config.yaml");
+
+        assert_eq!(resolve_config_path(Some(path.clone())).unwrap(), path);
+    }
+}
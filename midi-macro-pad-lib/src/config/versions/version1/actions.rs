@@ -0,0 +1,38 @@
+use crate::config::ConfigError;
+use crate::config::raw_config::RawConfig;
+use crate::macros::actions::Action;
+
+mod combination;
+mod enter_text;
+mod key_down;
+mod key_sequence;
+mod key_up;
+mod shell;
+
+pub use combination::build_action_combination;
+pub use enter_text::build_action_enter_text;
+pub use key_down::build_action_key_down;
+pub use key_sequence::build_action_key_sequence;
+pub use key_up::build_action_key_up;
+pub use shell::build_action_shell;
+
+/// Builds an `Action` from its config `action_type` name (e.g. `"key_sequence"`) and `raw_data`,
+/// dispatching to the builder function registered for that type.
+///
+/// ## Errors
+/// Returns `ConfigError::InvalidConfig` if `action_type` isn't one of the registered action
+/// types, or if the registered builder itself fails.
+pub fn build_action(action_type: &str, raw_data: Option<&RawConfig>) -> Result<Action, ConfigError> {
+    match action_type {
+        "key_sequence" => build_action_key_sequence(raw_data),
+        "key_down" => build_action_key_down(raw_data),
+        "key_up" => build_action_key_up(raw_data),
+        "enter_text" => build_action_enter_text(raw_data),
+        "shell" => build_action_shell(raw_data),
+        "combination" => build_action_combination(raw_data),
+
+        _ => Err(ConfigError::InvalidConfig(
+            format!("Unrecognised action type '{}'", action_type)
+        )),
+    }
+}
@@ -0,0 +1,69 @@
+use crate::config::raw_config::{RawConfig, AccessHelpers};
+use crate::macros::actions::Action;
+use crate::config::ConfigError;
+
+use super::build_action;
+
+/// Constructs an `Action::Combination` from `raw_data` `RawConfig`.
+///
+/// `raw_data` must be a `RawConfig::Array`, where each item is itself a `RawConfig::Hash`
+/// describing one nested action, in the same `type`/`data` shape as a macro's top-level action
+/// entries:
+/// ```yaml
+/// - type: key_down
+///   data: "Control_L"
+/// - type: key_sequence
+///   data: "t"
+/// ```
+///
+/// Each item's `type` is required and should be a String naming a registered action type (see
+/// `build_action`). `data` is passed through to that type's builder, and is itself optional or
+/// required depending on that builder.
+///
+/// ## Errors
+/// The function returns `ConfigError` in any of the following circumstances:
+///
+/// - `raw_data` is None
+/// - `raw_data` is not a `RawConfig::Array`
+/// - any item of the array is not a `RawConfig::Hash`, or is missing a `type` field that is a
+///   `RawConfig::String`
+/// - any item's nested action fails to build
+pub fn build_action_combination(raw_data: Option<&RawConfig>) -> Result<Action, ConfigError> {
+    const TYPE_FIELD: &str = "type";
+    const DATA_FIELD: &str = "data";
+
+    let raw_data = raw_data.ok_or_else(|| {
+        ConfigError::InvalidConfig(
+            format!("Action combination: missing data field")
+        )
+    })?;
+
+    match raw_data {
+        RawConfig::Array(items) => {
+            let actions = items.iter()
+                .map(|item| match item {
+                    RawConfig::Hash(hash) => {
+                        let action_type = hash.get_string(TYPE_FIELD).ok_or_else(|| {
+                            ConfigError::InvalidConfig(format!(
+                                "Action combination: item doesn't contain a '{}' field",
+                                TYPE_FIELD
+                            ))
+                        })?;
+
+                        build_action(action_type, hash.get_raw(DATA_FIELD))
+                    },
+
+                    _ => Err(ConfigError::InvalidConfig(format!(
+                        "Action combination: item should be a hash, but was not"
+                    )))
+                })
+                .collect::<Result<Vec<Action>, ConfigError>>()?;
+
+            Ok(Action::Combination(actions))
+        }
+
+        _ => Err(ConfigError::InvalidConfig(format!(
+            "Action combination: data field should be an array, but was not"
+        )))
+    }
+}
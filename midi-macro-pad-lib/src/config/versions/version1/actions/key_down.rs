@@ -0,0 +1,29 @@
+use crate::config::raw_config::RawConfig;
+use crate::macros::actions::Action;
+use crate::config::ConfigError;
+
+/// Constructs an `Action::KeyDown` from `raw_data` `RawConfig`.
+///
+/// `raw_data` must be a `RawConfig::String` naming the key or modifier to press and hold, in
+/// X Keysym notation, e.g. "Super_L" or "Control_L".
+///
+/// ## Errors
+/// The function returns `ConfigError` in any of the following circumstances:
+///
+/// - `raw_data` is None
+/// - `raw_data` is not a `RawConfig::String`
+pub fn build_action_key_down(raw_data: Option<&RawConfig>) -> Result<Action, ConfigError> {
+    let raw_data = raw_data.ok_or_else(|| {
+        ConfigError::InvalidConfig(
+            format!("Action key_down: missing data field")
+        )
+    })?;
+
+    match raw_data {
+        RawConfig::String(key) => Ok(Action::KeyDown(key.to_string())),
+
+        _ => Err(ConfigError::InvalidConfig(format!(
+            "Action key_down: data field should be a string naming the key to press, but was not"
+        )))
+    }
+}
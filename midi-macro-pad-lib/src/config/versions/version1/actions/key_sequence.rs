@@ -21,6 +21,11 @@ use crate::config::ConfigError;
 /// When specified as a `RawConfig::String`, or omitted in a `RawConfig::Hash`, `count` will default
 /// to 1.
 ///
+/// `sequence` is kept as-is here rather than parsed into a `KeyCombo`: it may reference variables
+/// from the triggering `Context` using `$(VAR)`/`${VAR}` notation (e.g. "ctrl+${note}"), which
+/// can't be resolved until a macro actually fires. It's expanded and parsed at dispatch time (see
+/// `ActionRunner::run_with_context`), so a typo in a modifier name surfaces there instead.
+///
 /// ## Errors
 /// The function return `ConfigError` in any of the following circumstances:
 ///
@@ -40,7 +45,7 @@ pub fn build_action_key_sequence(raw_data: Option<&RawConfig>) -> Result<Action,
     })?;
 
     match raw_data {
-        RawConfig::String(sequence) => Ok(Action::KeySequence(sequence.to_string(), 1)),
+        RawConfig::String(sequence) => Ok(Action::KeySequence(sequence, 1)),
 
         RawConfig::Hash(hash) => {
             let sequence = hash.get_string(SEQUENCE_FIELD).ok_or_else(|| {
@@ -57,7 +62,7 @@ pub fn build_action_key_sequence(raw_data: Option<&RawConfig>) -> Result<Action,
                     format!("Action key_sequence: count should be 0 or more, found {}", count)
                 ))
             } else {
-                Ok(Action::KeySequence(sequence.to_string(), count as usize))
+                Ok(Action::KeySequence(sequence, count as usize))
             }
         }
 
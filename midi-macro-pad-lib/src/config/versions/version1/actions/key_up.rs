@@ -0,0 +1,30 @@
+use crate::config::raw_config::RawConfig;
+use crate::macros::actions::Action;
+use crate::config::ConfigError;
+
+/// Constructs an `Action::KeyUp` from `raw_data` `RawConfig`.
+///
+/// `raw_data` must be a `RawConfig::String` naming the key or modifier to release, in X Keysym
+/// notation, e.g. "Super_L" or "Control_L". It should match a key previously pressed with
+/// `key_down`.
+///
+/// ## Errors
+/// The function returns `ConfigError` in any of the following circumstances:
+///
+/// - `raw_data` is None
+/// - `raw_data` is not a `RawConfig::String`
+pub fn build_action_key_up(raw_data: Option<&RawConfig>) -> Result<Action, ConfigError> {
+    let raw_data = raw_data.ok_or_else(|| {
+        ConfigError::InvalidConfig(
+            format!("Action key_up: missing data field")
+        )
+    })?;
+
+    match raw_data {
+        RawConfig::String(key) => Ok(Action::KeyUp(key.to_string())),
+
+        _ => Err(ConfigError::InvalidConfig(format!(
+            "Action key_up: data field should be a string naming the key to release, but was not"
+        )))
+    }
+}
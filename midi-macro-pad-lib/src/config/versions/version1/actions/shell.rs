@@ -0,0 +1,64 @@
+use crate::config::raw_config::{RawConfig, AccessHelpers};
+use crate::macros::actions::Action;
+use crate::config::ConfigError;
+
+/// Constructs an `Action::Shell` from `raw_data` `RawConfig`.
+///
+/// `raw_data` must be a `RawConfig::Hash`, as follows:
+/// ```yaml
+/// command: "/path/to/program"
+/// args:
+///   - "--some-flag"
+///   - "some value"
+/// env_vars:
+///   SOME_VAR: "some value"
+/// ```
+///
+/// `command` is required and should be a String naming the program to run.
+///
+/// `args` is optional and should be a list of Strings, passed to `command` as arguments.
+///
+/// `env_vars` is optional and should be a map of String to String, passed to `command` as
+/// environment variables.
+///
+/// `command`, each item of `args`, and the values of `env_vars` may reference variables from the
+/// triggering `Context` using `$(VAR)`/`${VAR}` notation.
+///
+/// ## Errors
+/// The function returns `ConfigError` in any of the following circumstances:
+///
+/// - `raw_data` is None
+/// - `raw_data` is not a `RawConfig::Hash`
+/// - `raw_data` is a `RawConfig::Hash` but is missing a `command` field that is a
+///   `RawConfig::String`
+pub fn build_action_shell(raw_data: Option<&RawConfig>) -> Result<Action, ConfigError> {
+    const COMMAND_FIELD: &str = "command";
+    const ARGS_FIELD: &str = "args";
+    const ENV_VARS_FIELD: &str = "env_vars";
+
+    let raw_data = raw_data.ok_or_else(|| {
+        ConfigError::InvalidConfig(
+            format!("Action shell: missing data field")
+        )
+    })?;
+
+    match raw_data {
+        RawConfig::Hash(hash) => {
+            let command = hash.get_string(COMMAND_FIELD).ok_or_else(|| {
+                ConfigError::InvalidConfig(format!(
+                    "Action shell: data field doesn't contain a '{}' field",
+                    COMMAND_FIELD
+                ))
+            })?;
+
+            let args = hash.get_string_list(ARGS_FIELD);
+            let env_vars = hash.get_string_pairs(ENV_VARS_FIELD);
+
+            Ok(Action::Shell { command, args, env_vars })
+        }
+
+        _ => Err(ConfigError::InvalidConfig(format!(
+            "Action shell: data field should be a hash, but was not"
+        )))
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+use notify::{DebouncedEvent, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::config::Config;
+use crate::config::loader::load_config_file;
+
+/// Watches `path` on a background thread and, whenever it changes, re-parses it and atomically
+/// swaps the `Config` behind `config` for the freshly parsed one.
+///
+/// If re-parsing fails, the error is logged to STDERR and the last-good `Config` keeps running
+/// unchanged, so a typo in the config never interrupts an in-progress listen loop.
+pub fn watch_config(path: PathBuf, config: Arc<RwLock<Config>>) {
+    thread::spawn(move || {
+        let (tx, rx) = channel();
+
+        let mut watcher: RecommendedWatcher = match Watcher::new(tx, Duration::from_secs(1)) {
+            Ok(watcher) => watcher,
+
+            Err(e) => {
+                eprintln!("Unable to set up config file watcher: {}", e);
+                return;
+            }
+        };
+
+        if let Err(e) = watcher.watch(&path, RecursiveMode::NonRecursive) {
+            eprintln!("Unable to watch config file '{}': {}", path.display(), e);
+            return;
+        }
+
+        for event in rx {
+            if !is_modification(&event) {
+                continue;
+            }
+
+            match load_config_file(&path) {
+                Ok(new_config) => {
+                    *config.write().unwrap_or_else(|p| p.into_inner()) = new_config;
+                    println!("Config reloaded from: {}", path.display());
+                },
+
+                Err(e) => {
+                    eprintln!(
+                        "Config file changed but failed to reload, keeping last-good config: {}",
+                        e
+                    );
+                }
+            }
+        }
+    });
+}
+
+fn is_modification(event: &DebouncedEvent) -> bool {
+    matches!(event, DebouncedEvent::Write(_) | DebouncedEvent::Create(_) | DebouncedEvent::Chmod(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_create_and_chmod_are_modifications() {
+        assert!(is_modification(&DebouncedEvent::Write(PathBuf::from("config.yaml"))));
+        assert!(is_modification(&DebouncedEvent::Create(PathBuf::from("config.yaml"))));
+        assert!(is_modification(&DebouncedEvent::Chmod(PathBuf::from("config.yaml"))));
+    }
+
+    #[test]
+    fn other_event_kinds_are_not_modifications() {
+        assert!(!is_modification(&DebouncedEvent::Remove(PathBuf::from("config.yaml"))));
+        assert!(!is_modification(&DebouncedEvent::Rescan));
+    }
+}
@@ -0,0 +1,43 @@
+use std::io;
+
+use thiserror::Error;
+
+/// Top-level error type for everything that can go wrong while discovering, loading, or acting on
+/// config, or while setting up the MIDI/focus/keyboard adapters at startup.
+///
+/// No user-reachable input (a missing file, malformed config, or unavailable adapter) should ever
+/// panic the process; it should surface here instead.
+#[derive(Debug, Error)]
+pub enum MmpdError {
+    /// Reading a config file (or any other file) from disk failed.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// No config file could be found in any of the searched locations.
+    #[error("{0}")]
+    ConfigNotFound(String),
+
+    /// A config file was found and read, but failed to parse or process.
+    #[error("{0}")]
+    InvalidConfig(String),
+
+    /// The config file's extension has no registered parser.
+    #[error("no config parser available for extension '{0}'")]
+    UnrecognisedConfigExtension(String),
+
+    /// The MIDI adapter for this platform could not be initialized.
+    #[error("unable to initialize MIDI adapter")]
+    MidiAdapterUnavailable,
+
+    /// Connecting to the requested MIDI port and starting to listen failed.
+    #[error("unable to start listening for MIDI events")]
+    MidiListenFailed,
+
+    /// The window-focus adapter for this platform could not be initialized.
+    #[error("unable to set up focus adapter - can't detect focused window")]
+    FocusAdapterUnavailable,
+
+    /// The keyboard-control adapter for this platform could not be initialized.
+    #[error("unable to get an action runner")]
+    ActionRunnerUnavailable,
+}
@@ -0,0 +1,83 @@
+use std::process::Command;
+
+use crate::macros::actions::KeyCombo;
+use crate::macros::actions::key_combo::Modifier;
+
+/// Adapter trait for whatever mechanism actually drives the keyboard on this platform.
+pub trait KeyboardControlAdapter {
+    /// Presses and releases every key in `combo` together, once.
+    fn send_keysequence(&self, combo: &KeyCombo, delay_between_keys_us: u32);
+
+    /// Types `text` out as if entered on a keyboard.
+    fn send_text(&self, text: &str, delay_between_keys_us: u32);
+
+    /// Presses `key` (in X Keysym notation) and holds it down without releasing it.
+    fn send_keydown(&self, key: &str);
+
+    /// Releases `key` (in X Keysym notation) previously pressed with `send_keydown`.
+    fn send_keyup(&self, key: &str);
+}
+
+/// Returns a `KeyboardControlAdapter` for this platform, or `None` if one couldn't be set up.
+pub fn get_adapter() -> Option<Box<dyn KeyboardControlAdapter>> {
+    Some(Box::new(XdotoolKeyboardControlAdapter))
+}
+
+/// Drives the keyboard by shelling out to `xdotool`.
+struct XdotoolKeyboardControlAdapter;
+
+impl XdotoolKeyboardControlAdapter {
+    fn modifier_key_name(modifier: &Modifier) -> &'static str {
+        match modifier {
+            Modifier::Super => "super",
+            Modifier::Hyper => "hyper",
+            Modifier::Meta => "meta",
+            Modifier::Alt => "alt",
+            Modifier::Control => "ctrl",
+            Modifier::Shift => "shift",
+            Modifier::ModeSwitch => "mode_switch",
+            Modifier::Lock => "lock",
+            Modifier::Mod1 => "mod1",
+            Modifier::Mod2 => "mod2",
+            Modifier::Mod3 => "mod3",
+            Modifier::Mod4 => "mod4",
+            Modifier::Mod5 => "mod5",
+        }
+    }
+
+    fn delay_ms(delay_between_keys_us: u32) -> String {
+        (delay_between_keys_us / 1000).max(1).to_string()
+    }
+}
+
+impl KeyboardControlAdapter for XdotoolKeyboardControlAdapter {
+    fn send_keysequence(&self, combo: &KeyCombo, delay_between_keys_us: u32) {
+        let mut keys: Vec<&str> = combo.modifiers.iter()
+            .map(Self::modifier_key_name)
+            .collect();
+
+        keys.push(&combo.keysym);
+
+        let _ = Command::new("xdotool")
+            .arg("key")
+            .arg("--delay").arg(Self::delay_ms(delay_between_keys_us))
+            .arg(keys.join("+"))
+            .status();
+    }
+
+    fn send_text(&self, text: &str, delay_between_keys_us: u32) {
+        let _ = Command::new("xdotool")
+            .arg("type")
+            .arg("--delay").arg(Self::delay_ms(delay_between_keys_us))
+            .arg(text)
+            .status();
+    }
+
+    fn send_keydown(&self, key: &str) {
+        let _ = Command::new("xdotool").arg("keydown").arg(key).status();
+    }
+
+    fn send_keyup(&self, key: &str) {
+        let _ = Command::new("xdotool").arg("keyup").arg(key).status();
+    }
+}
@@ -0,0 +1,204 @@
+use crate::keyboard_control::{self, KeyboardControlAdapter};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::process::Command;
+
+pub mod key_combo;
+pub mod template;
+
+pub use key_combo::KeyCombo;
+pub use template::Context;
+
+/// Action run in response to a MIDI event
+/// Any Action value can be run through ActionRunner::run.
+pub enum Action<'a> {
+    /// Sends a key sequence 0 or more times
+    /// Use this one for key combinations.
+    /// The string is hotkey-style notation (e.g. "ctrl+shift+t") and may reference variables from
+    /// the triggering `Context` using `$(VAR)`/`${VAR}` notation. It's expanded and parsed into a
+    /// `KeyCombo` at dispatch time, after substitution, since a combo referencing `Context`
+    /// variables (e.g. "ctrl+${note}") can't be validated any earlier than that.
+    /// The number is how many times this key sequence should be entered.
+    KeySequence(&'a str, usize),
+
+    /// Enters text as if you typed it on a keyboard
+    /// Use this one for text exactly as in the string provided.
+    /// The number is how many times this same string should be entered.
+    /// May reference variables from the triggering `Context` using `$(VAR)`/`${VAR}` notation.
+    EnterText(&'a str, usize),
+
+    /// Runs a program using the shell, allows running arbitrary programs.
+    /// `command`, each item of `args`, and the values (not the keys) of `env_vars` may reference
+    /// variables from the triggering `Context` using `$(VAR)`/`${VAR}` notation.
+    Shell {
+        /// Absolute path to the program to run, without any arguments or options
+        command: &'a str,
+
+        /// A list of arguments provided to the command. Each item is passed to the program as
+        /// one separate argument, as-is, so an item that includes spaces is not split up.
+        args: Option<Vec<&'a str>>,
+
+        /// A list of key/value pairs with environment variables to be provided to the program
+        env_vars: Option<Vec<(&'a str, &'a str)>>
+    },
+
+    /// Presses and holds a single key or modifier (e.g. "Super_L", "Control_L", "Shift"), in
+    /// X Keysym notation, without releasing it.
+    /// Use this together with `KeyUp` for chord-style mappings, where one pad holds a modifier
+    /// down while other pads tap keys or notes.
+    KeyDown(String),
+
+    /// Releases a single key or modifier previously pressed with `KeyDown`.
+    KeyUp(String),
+
+    /// A list of actions to be run in the order specified, to allow executing several different
+    /// ones for more complex things.
+    Combination(Vec<Action<'a>>),
+
+    // This can be expanded upon
+}
+
+const DELAY_BETWEEN_KEYS_US: u32 = 100;
+
+/// Struct to give access to running Actions
+pub struct ActionRunner {
+    kb_adapter: Box<dyn KeyboardControlAdapter>,
+
+    /// Keys/modifiers currently held down via `Action::KeyDown`, so they can be released again
+    /// on shutdown or when a macro sequence is interrupted.
+    held_keys: RefCell<HashSet<String>>
+}
+
+impl ActionRunner {
+    /// Set up a new ActionRunner, relying on getting an adapter from keyboard_control.
+    /// If no keyboard_control adapter can be obtained, returns None.
+    pub fn new() -> Option<ActionRunner> {
+        Some(ActionRunner {
+            kb_adapter: keyboard_control::get_adapter()?,
+            held_keys: RefCell::new(HashSet::new())
+        })
+    }
+
+    /// Executes a given action based on action type, against an empty `Context`: any
+    /// `$(VAR)`/`${VAR}` references in its strings expand to empty strings, and `$$` to `$`.
+    pub fn run(&self, action: &Action) {
+        self.run_with_context(action, &Context::new());
+    }
+
+    /// Executes a given action based on action type, first expanding any `$(VAR)`/`${VAR}`
+    /// references in its strings against `context`.
+    pub fn run_with_context(&self, action: &Action, context: &Context) {
+        match action {
+            Action::KeySequence(sequence, count) => {
+                self.run_key_sequence(&template::expand(sequence, context), *count);
+            },
+
+            Action::EnterText(text, count) => {
+                self.run_enter_text(&template::expand(text, context), *count);
+            },
+
+            Action::Shell { command, args, env_vars } => {
+                self.run_shell(
+                    &template::expand(command, context),
+                    args.as_ref().map(|args| {
+                        args.iter().map(|arg| template::expand(arg, context)).collect()
+                    }),
+                    env_vars.as_ref().map(|env_vars| {
+                        env_vars.iter()
+                            .map(|(key, val)| (key.to_string(), template::expand(val, context)))
+                            .collect()
+                    })
+                );
+            },
+
+            Action::KeyDown(key) => {
+                self.run_key_down(&template::expand(key, context));
+            },
+
+            Action::KeyUp(key) => {
+                self.run_key_up(&template::expand(key, context));
+            },
+
+            Action::Combination(actions) => {
+                for action in actions {
+                    self.run_with_context(action, context);
+                }
+            },
+        }
+    }
+
+    /// Releases every key/modifier currently held down via `Action::KeyDown`.
+    ///
+    /// Call this on shutdown, or when a macro sequence is interrupted, so no key is left stuck
+    /// in the pressed state.
+    pub fn release_held_keys(&self) {
+        for key in self.held_keys.borrow_mut().drain() {
+            self.kb_adapter.send_keyup(&key);
+        }
+    }
+
+    fn run_key_down(&self, key: &str) {
+        self.kb_adapter.send_keydown(key);
+        self.held_keys.borrow_mut().insert(key.to_string());
+    }
+
+    fn run_key_up(&self, key: &str) {
+        self.kb_adapter.send_keyup(key);
+        self.held_keys.borrow_mut().remove(key);
+    }
+
+    /// Parses `sequence` (already expanded against the triggering `Context`) into a `KeyCombo`
+    /// and sends it `count` times. Since `sequence` may have come from a template substitution,
+    /// it's validated here rather than at config load time; a combo that fails to parse is logged
+    /// and skipped, rather than panicking the listen loop.
+    fn run_key_sequence(&self, sequence: &str, count: usize) {
+        match key_combo::parse_combo(sequence) {
+            Ok(combo) => {
+                for _ in 0..count {
+                    self.kb_adapter.send_keysequence(&combo, DELAY_BETWEEN_KEYS_US);
+                }
+            },
+
+            Err(e) => {
+                eprintln!("Invalid key sequence '{}': {}", sequence, e);
+            },
+        }
+    }
+
+    fn run_enter_text(&self, text: &str, count: usize) {
+        for _ in 0..count {
+            self.kb_adapter.send_text(text, DELAY_BETWEEN_KEYS_US);
+        }
+    }
+
+    fn run_shell(
+        &self,
+        command: &str,
+        args: Option<Vec<String>>,
+        env_vars: Option<Vec<(String, String)>>
+    ) {
+        let mut cmd = Command::new(command);
+
+        // Attach any arguments
+        if let Some(args) = args {
+            for arg in args.iter() {
+                cmd.arg(arg);
+            }
+        }
+
+        // Attach any environment variables
+        if let Some(env_vars) = env_vars {
+            for (env_key, env_val) in env_vars {
+                cmd.env(env_key, env_val);
+            }
+        }
+
+        // Run
+        let _ = cmd.status();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // TODO: add a mocking library to test actions
+}
\ No newline at end of file
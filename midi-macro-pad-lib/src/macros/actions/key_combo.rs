@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+use std::fmt;
+
+/// A modifier key that can be held as part of a key combination.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Modifier {
+    Super,
+    Hyper,
+    Meta,
+    Alt,
+    Control,
+    Shift,
+    ModeSwitch,
+    Lock,
+    Mod1,
+    Mod2,
+    Mod3,
+    Mod4,
+    Mod5,
+}
+
+impl Modifier {
+    fn parse(name: &str) -> Option<Modifier> {
+        match name.to_lowercase().as_str() {
+            "super" => Some(Modifier::Super),
+            "hyper" => Some(Modifier::Hyper),
+            "meta" => Some(Modifier::Meta),
+            "alt" => Some(Modifier::Alt),
+            "control" | "ctrl" => Some(Modifier::Control),
+            "shift" => Some(Modifier::Shift),
+            "modeswitch" | "mode_switch" => Some(Modifier::ModeSwitch),
+            "lock" => Some(Modifier::Lock),
+            "mod1" => Some(Modifier::Mod1),
+            "mod2" => Some(Modifier::Mod2),
+            "mod3" => Some(Modifier::Mod3),
+            "mod4" => Some(Modifier::Mod4),
+            "mod5" => Some(Modifier::Mod5),
+            _ => None,
+        }
+    }
+}
+
+/// A validated key combination: a set of held modifiers plus a single final keysym.
+///
+/// For example, `"ctrl+shift+t"` decomposes into the modifiers `{Control, Shift}` and the keysym
+/// `"t"`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyCombo {
+    pub modifiers: HashSet<Modifier>,
+    pub keysym: String,
+}
+
+/// Error returned by `parse_combo` when a combo string names an unrecognised modifier or keysym.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KeyComboParseError {
+    UnknownModifier(String),
+    UnknownKeysym(String),
+}
+
+impl fmt::Display for KeyComboParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyComboParseError::UnknownModifier(modifier) => {
+                write!(f, "unknown modifier '{}'", modifier)
+            },
+
+            KeyComboParseError::UnknownKeysym(keysym) => {
+                write!(f, "unknown keysym '{}'", keysym)
+            },
+        }
+    }
+}
+
+/// Named X Keysyms recognised on top of single-character keysyms (e.g. "t", "1", "!") and
+/// function keys ("F1" through "F35").
+const NAMED_KEYSYMS: &[&str] = &[
+    "BackSpace", "Tab", "Return", "Escape", "space", "Delete",
+    "Home", "End", "Page_Up", "Page_Down", "Up", "Down", "Left", "Right",
+    "Insert", "Print", "Pause", "Caps_Lock", "Num_Lock", "Scroll_Lock", "Menu",
+];
+
+/// Returns whether `keysym` is a single character, a named keysym from `NAMED_KEYSYMS`, or a
+/// function key name "F1" through "F35".
+fn is_known_keysym(keysym: &str) -> bool {
+    if keysym.chars().count() == 1 {
+        return true;
+    }
+
+    if let Some(digits) = keysym.strip_prefix('F') {
+        if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+            if let Ok(n) = digits.parse::<u8>() {
+                return (1..=35).contains(&n);
+            }
+        }
+    }
+
+    NAMED_KEYSYMS.contains(&keysym)
+}
+
+/// Parses a hotkey-style combo string, e.g. `"ctrl+shift+t"`, into a `KeyCombo`.
+///
+/// The string is split on `+`; every part but the last must name a known `Modifier` (matched
+/// case-insensitively), and the last part is taken as the final keysym, which must be a known
+/// keysym (see `is_known_keysym`).
+///
+/// ## Errors
+/// Returns `KeyComboParseError` if any part but the last doesn't match a known modifier name, or
+/// if the final part isn't a recognised keysym (including if it's empty, e.g. from `"ctrl+"`).
+pub fn parse_combo(combo: &str) -> Result<KeyCombo, KeyComboParseError> {
+    let mut parts: Vec<&str> = combo.split('+').map(|part| part.trim()).collect();
+
+    let keysym = parts.pop().unwrap_or("").to_string();
+
+    if !is_known_keysym(&keysym) {
+        return Err(KeyComboParseError::UnknownKeysym(keysym));
+    }
+
+    let mut modifiers = HashSet::new();
+
+    for part in parts {
+        let modifier = Modifier::parse(part)
+            .ok_or_else(|| KeyComboParseError::UnknownModifier(part.to_string()))?;
+
+        modifiers.insert(modifier);
+    }
+
+    Ok(KeyCombo { modifiers, keysym })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_plain_keysym_with_no_modifiers() {
+        let combo = parse_combo("t").unwrap();
+
+        assert_eq!(combo.modifiers, HashSet::new());
+        assert_eq!(combo.keysym, "t");
+    }
+
+    #[test]
+    fn parses_modifiers_case_insensitively() {
+        let combo = parse_combo("Ctrl+SHIFT+t").unwrap();
+
+        assert_eq!(combo.modifiers, [Modifier::Control, Modifier::Shift].iter().cloned().collect());
+        assert_eq!(combo.keysym, "t");
+    }
+
+    #[test]
+    fn parses_named_keysyms_and_function_keys() {
+        assert!(parse_combo("ctrl+Return").is_ok());
+        assert!(parse_combo("ctrl+F12").is_ok());
+    }
+
+    #[test]
+    fn rejects_an_unknown_modifier() {
+        let error = parse_combo("ctrl+shiift+t").unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown modifier 'shiift'");
+    }
+
+    #[test]
+    fn rejects_an_unknown_keysym() {
+        let error = parse_combo("ctrl+definitelynotakey").unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown keysym 'definitelynotakey'");
+    }
+
+    #[test]
+    fn rejects_an_empty_keysym() {
+        let error = parse_combo("ctrl+").unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown keysym ''");
+    }
+
+    #[test]
+    fn rejects_an_out_of_range_function_key() {
+        let error = parse_combo("F99").unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown keysym 'F99'");
+    }
+}
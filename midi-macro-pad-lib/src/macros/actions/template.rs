@@ -0,0 +1,152 @@
+use std::collections::HashMap;
+
+/// A set of named values available for substitution into an action's strings at dispatch time.
+///
+/// A `Context` is typically built from the fields of the `Event` that triggered a macro (e.g.
+/// `note`, `velocity`, `channel`, `control`, `value` for MIDI events), and may later also carry
+/// values drawn from the in-memory MIDI `State`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Context {
+    values: HashMap<String, String>,
+}
+
+impl Context {
+    /// Creates an empty `Context` with no substitutable values.
+    pub fn new() -> Context {
+        Context { values: HashMap::new() }
+    }
+
+    /// Inserts a value for `name`, overwriting any previous value for the same name.
+    pub fn insert<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    /// Looks up the value stored for `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(|v| v.as_str())
+    }
+}
+
+/// A single piece of a tokenized template string.
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Literal(String),
+    Variable(String),
+}
+
+/// Splits `template` into a sequence of literal spans and variable references.
+///
+/// Variables may be written as `$(VAR)` or `${VAR}`, Makefile-style. `$$` is an escape for a
+/// literal `$`. A `$` that isn't followed by `$`, `(` or `{` is treated as a literal character.
+fn tokenize(template: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut literal = String::new();
+    let chars: Vec<char> = template.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c != '$' {
+            literal.push(c);
+            i += 1;
+            continue;
+        }
+
+        match chars.get(i + 1) {
+            Some('$') => {
+                literal.push('$');
+                i += 2;
+            }
+
+            Some(&open @ '(') | Some(&open @ '{') => {
+                let close = if open == '(' { ')' } else { '}' };
+
+                if let Some(close_offset) = chars[i + 2..].iter().position(|c| *c == close) {
+                    if !literal.is_empty() {
+                        tokens.push(Token::Literal(literal.clone()));
+                        literal.clear();
+                    }
+
+                    let name: String = chars[i + 2..i + 2 + close_offset].iter().collect();
+                    tokens.push(Token::Variable(name));
+                    i += 2 + close_offset + 1;
+                } else {
+                    // No closing delimiter found; keep this `$` as a literal and carry on
+                    // scanning after it.
+                    literal.push(c);
+                    i += 1;
+                }
+            }
+
+            _ => {
+                literal.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+
+    tokens
+}
+
+/// Expands `template` against `context`, substituting `$(VAR)`/`${VAR}` references with the
+/// matching value from `context`.
+///
+/// A variable with no matching entry in `context` expands to an empty string. `$$` expands to a
+/// literal `$`.
+pub fn expand(template: &str, context: &Context) -> String {
+    tokenize(template)
+        .into_iter()
+        .map(|token| match token {
+            Token::Literal(text) => text,
+            Token::Variable(name) => context.get(&name).unwrap_or("").to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context_with(pairs: &[(&str, &str)]) -> Context {
+        let mut context = Context::new();
+
+        for (name, value) in pairs {
+            context.insert(name.to_string(), value.to_string());
+        }
+
+        context
+    }
+
+    #[test]
+    fn expands_known_variables_in_both_notations() {
+        let context = context_with(&[("note", "60"), ("velocity", "127")]);
+
+        assert_eq!(expand("note=$(note) velocity=${velocity}", &context), "note=60 velocity=127");
+    }
+
+    #[test]
+    fn unknown_variables_expand_to_empty_string() {
+        let context = Context::new();
+
+        assert_eq!(expand("value=$(missing)!", &context), "value=!");
+    }
+
+    #[test]
+    fn dollar_dollar_escapes_a_literal_dollar() {
+        let context = Context::new();
+
+        assert_eq!(expand("cost: $$5", &context), "cost: $5");
+    }
+
+    #[test]
+    fn unterminated_variable_is_kept_as_literal() {
+        let context = Context::new();
+
+        assert_eq!(expand("$(unterminated", &context), "$(unterminated");
+    }
+}